@@ -45,8 +45,11 @@ impl Registry {
     pub fn take_prop(&mut self, t: usize, name: &str, is_set: bool) -> Result<PropCb, MethodErr> {
         let pdesc = self.0[t].properties.get_mut(name).ok_or_else(|| MethodErr::no_property(name))?;
         let cb = if is_set { pdesc.set_cb.take() } else { pdesc.get_cb.take() };
-        let rw = if is_set { "writable" } else { "readable" };
-        let cb = cb.ok_or_else(|| MethodErr::failed(&format!("Property {} is not {}", name, rw)))?;
+        let cb = cb.ok_or_else(|| if is_set {
+            MethodErr::ro_property(name)
+        } else {
+            MethodErr::failed(&format!("Property {} is not readable", name))
+        })?;
         Ok(cb.0)
     }
 
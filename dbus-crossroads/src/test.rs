@@ -148,6 +148,22 @@ const INTROSPECT: &str = r###"<!DOCTYPE node PUBLIC "-//freedesktop//DTD D-BUS O
   <node name="very/deep/child"/>
 </node>"###;
 
+#[test]
+fn set_read_only() {
+    let mut cr = Crossroads::new();
+    let iface = cr.register("com.example.dbusrs.readonly", |b: &mut IfaceBuilder<()>| {
+        b.property("Fixed").get(|_, _| Ok(5u32));
+    });
+    cr.insert("/", &[iface], ());
+
+    let msg = Message::call_with_args("com.example.dbusrs.readonly", "/",
+        "org.freedesktop.DBus.Properties", "Set", ("com.example.dbusrs.readonly", "Fixed", Variant(7u32)));
+    let mut r = dispatch_helper2(&mut cr, msg);
+    assert_eq!(r.len(), 1);
+    assert_eq!(r[0].msg_type(), dbus::message::MessageType::Error);
+    assert_eq!(&*r[0].as_result().unwrap_err().name().unwrap(), "org.freedesktop.DBus.Error.PropertyReadOnly");
+}
+
 #[test]
 fn introspect() {
     let mut cr = Crossroads::new();
@@ -185,6 +185,13 @@ macro_rules! string_wrapper {
         impl $t {
             /// Type conversion to DBusStr.
             pub fn as_dbus_str(&self) -> &DBusStr { DBusStr::new_unchecked(self) }
+
+            /// Checks whether s is valid for this type.
+            ///
+            /// Unlike `is_valid` this is a const fn, so it can be used for checks at compile time.
+            pub const fn is_valid_const(s: &str) -> bool {
+                validity::$validate(s.as_bytes()).is_ok()
+            }
         }
 
         impl From<$towned> for DBusString {
@@ -267,17 +274,94 @@ impl From<SignatureSingleBuf> for SignatureMultiBuf {
 }
 
 
+impl SignatureSingle {
+    /// Like `is_valid_const`, but tells what is wrong and at which byte offset,
+    /// e g (0, "empty struct") for "()".
+    pub const fn check_const(s: &str) -> Result<(), (usize, &'static str)> {
+        validity::signature_single_error(s.as_bytes())
+    }
+
+    /// Returns the length of the first single complete type in s, or None if s does not
+    /// start with one. E g, for "a{sv}i" this returns Some(5).
+    pub const fn first_len(s: &str) -> Option<usize> {
+        validity::single_len(s.as_bytes())
+    }
+}
+
 impl SignatureMulti {
+    /// Like `is_valid_const`, but tells what is wrong and at which byte offset,
+    /// e g (0, "missing ')'") for "(ii".
+    pub const fn check_const(s: &str) -> Result<(), (usize, &'static str)> {
+        validity::signature_multi_error(s.as_bytes())
+    }
+
+    /// Describes the types of s in human terms, e g "array of dict (string \u{2192} variant)"
+    /// for "a{sv}". Several complete types are separated by commas.
+    ///
+    /// This is a const fn, so it can be used at compile time. Returns the same error as
+    /// `check_const` if s is not a valid signature.
+    pub const fn explain_const(s: &str) -> Result<SignatureExplanation, (usize, &'static str)> {
+        match validity::signature_multi_error(s.as_bytes()) {
+            Ok(()) => Ok(validity::explain_multi(s.as_bytes())),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Describes the types of this signature in human terms, see `explain_const`.
+    pub fn explain(&self) -> SignatureExplanation {
+        validity::explain_multi(self.as_bytes())
+    }
+
     /// Splits this signature into the first and remaining parts.
     ///
     /// Returns none if the signature is empty.
     pub fn single(&self) -> Option<(&SignatureSingle, &SignatureMulti)> {
-        validity::sig_single(self.as_bytes(), 0, 0).map(|x|
+        validity::single_len(self.as_bytes()).map(|x|
             (SignatureSingle::new_unchecked(&self[0..x]), SignatureMulti::new_unchecked(&self[x..]))
         )
     }
 }
 
+/// A human readable description of a signature, as returned by `SignatureMulti::explain`.
+///
+/// It is kept in a fixed size buffer, so that it can be built by a const fn.
+#[derive(Clone)]
+pub struct SignatureExplanation {
+    buf: [u8; 4096],
+    len: usize,
+}
+
+impl SignatureExplanation {
+    const fn new() -> Self { SignatureExplanation { buf: [0; 4096], len: 0 } }
+
+    // A signature is at most 255 bytes, so the buffer is never full.
+    const fn push(&mut self, s: &str) {
+        let s = s.as_bytes();
+        let mut i = 0;
+        while i < s.len() {
+            self.buf[self.len] = s[i];
+            self.len += 1;
+            i += 1;
+        }
+    }
+
+    /// The description as a string slice.
+    pub const fn as_str(&self) -> &str {
+        match std::str::from_utf8(self.buf.split_at(self.len).0) {
+            Ok(s) => s,
+            Err(_) => "",
+        }
+    }
+}
+
+impl fmt::Display for SignatureExplanation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result { f.write_str(self.as_str()) }
+}
+
+impl fmt::Debug for SignatureExplanation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result { fmt::Debug::fmt(self.as_str(), f) }
+}
+
 impl Default for &SignatureMulti {
     fn default() -> Self { SignatureMulti::new_unchecked("") }
 }
@@ -307,6 +391,7 @@ string_wrapper!(
     ObjectPath, ObjectPathBuf, is_valid_object_path
 );
 
+
 #[test]
 fn type_conversions() {
     use std::borrow::Cow;
@@ -346,3 +431,43 @@ fn sig_split() {
 
     assert!(s4.single().is_none());
 }
+
+#[test]
+fn sig_const() {
+    const _: () = assert!(SignatureSingle::is_valid_const("a{sa{sv}}"));
+    assert!(!SignatureSingle::is_valid_const("a{vs}"));
+    assert!(SignatureMulti::is_valid_const("sa{sv}(ii)"));
+    assert!(!SignatureMulti::is_valid_const("(ii"));
+    assert_eq!(SignatureSingle::first_len("a{sv}i"), Some(5));
+    assert_eq!(SignatureSingle::first_len(""), None);
+    assert_eq!(SignatureSingle::check_const("a{sv}"), Ok(()));
+    assert_eq!(SignatureSingle::check_const("()"), Err((0, "empty struct")));
+    assert_eq!(SignatureMulti::check_const("(ii"), Err((0, "missing ')'")));
+
+    const _: () = assert!(ObjectPath::is_valid_const("/org/example"));
+    assert!(!ObjectPath::is_valid_const("/foo//bar"));
+    assert!(InterfaceName::is_valid_const("org.freedesktop.DBus"));
+    assert!(!InterfaceName::is_valid_const("org..freedesktop.DBus"));
+    assert!(BusName::is_valid_const(":1.54"));
+    assert!(MemberName::is_valid_const("GetAll"));
+    assert!(ErrorName::is_valid_const("org.freedesktop.DBus.Error.Failed"));
+}
+
+#[test]
+fn sig_explain() {
+    const E: SignatureExplanation = match SignatureMulti::explain_const("a{sv}") {
+        Ok(e) => e,
+        Err(_) => panic!(),
+    };
+    assert_eq!(E.as_str(), "array of dict (string \u{2192} variant)");
+    let s = SignatureMulti::new("a{oa{sa{sv}}}").unwrap();
+    assert_eq!(s.explain().to_string(), "array of dict (object path \u{2192} array of dict (string \u{2192} \
+        array of dict (string \u{2192} variant)))");
+    let s = SignatureMulti::new("u(ay(hb))ag").unwrap();
+    assert_eq!(s.explain().as_str(), "uint32, struct (array of byte, struct (unix fd, boolean)), array of signature");
+    assert_eq!(SignatureMulti::explain_const("").unwrap().as_str(), "");
+    assert_eq!(SignatureMulti::explain_const("a{vs}").unwrap_err(), (2, "dict key must be a basic type"));
+
+    let deep = format!("{}{}{}", "(".repeat(32), "o".repeat(150), ")".repeat(32));
+    assert!(SignatureMulti::new(&*deep).unwrap().explain().as_str().starts_with("struct (struct ("));
+}
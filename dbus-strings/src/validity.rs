@@ -1,25 +1,25 @@
-fn is_az_(b: u8) -> Result<(), ()> {
+const fn is_az_(b: u8) -> Result<(), ()> {
     match b {
         b'A'..=b'Z' | b'a'..=b'z' | b'_' => Ok(()),
         _ => Err(()),
     }
 }
 
-fn is_az09_(b: u8) -> Result<(), ()> {
+const fn is_az09_(b: u8) -> Result<(), ()> {
     match b {
         b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'_' => Ok(()),
         _ => Err(()),
     }
 }
 
-fn is_az_hyphen(b: u8) -> Result<(), ()> {
+const fn is_az_hyphen(b: u8) -> Result<(), ()> {
     match b {
         b'A'..=b'Z' | b'a'..=b'z' | b'_' | b'-' => Ok(()),
         _ => Err(()),
     }
 }
 
-fn is_az09_hyphen(b: u8) -> Result<(), ()> {
+const fn is_az09_hyphen(b: u8) -> Result<(), ()> {
     match b {
         b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'_' | b'-' => Ok(()),
         _ => Err(()),
@@ -34,141 +34,248 @@ pub fn is_valid_string(s: &str) -> Result<(), ()> {
     else if s.iter().any(|&b| b == 0) { Err(()) } else { Ok(()) }
 }
 
-pub fn is_valid_member_name(s: &[u8]) -> Result<(), ()> {
-    if s.len() > 255 { Err(())? }
-    let mut x = s.into_iter();
-    let c = *x.next().ok_or(())?;
-    is_az_(c)?;
-    for c in x { is_az09_(*c)? };
+pub const fn is_valid_member_name(s: &[u8]) -> Result<(), ()> {
+    if s.is_empty() || s.len() > 255 { return Err(()) }
+    if is_az_(s[0]).is_err() { return Err(()) }
+    let mut i = 1;
+    while i < s.len() {
+        if is_az09_(s[i]).is_err() { return Err(()) }
+        i += 1;
+    }
     Ok(())
 }
 
-pub fn is_valid_error_name(s: &[u8]) -> Result<(), ()> {
-    return is_valid_interface_name(s)
+pub const fn is_valid_error_name(s: &[u8]) -> Result<(), ()> {
+    is_valid_interface_name(s)
 }
 
-pub fn is_valid_interface_name(s: &[u8]) -> Result<(), ()> {
-    if s.len() > 255 { Err(())? }
-    let mut x = s.into_iter();
+// Checks a name consisting of two or more non-empty elements separated by dots.
+// Hyphens are allowed in bus names, and unique connection names may have elements starting
+// with a digit.
+const fn is_valid_dotted_name(s: &[u8], start: usize, hyphen: bool, digit_first: bool) -> Result<(), ()> {
     let mut elements = 1;
-    'outer: loop {
-        let c = *x.next().ok_or(())?;
-        is_az_(c)?;
-        while let Some(&c) = x.next() {
-            if c == b'.' {
-                elements += 1;
-                continue 'outer;
-            }
-            is_az09_(c)?;
-        }
-        return if elements > 1 { Ok(()) } else { Err(()) }
+    let mut element_start = true;
+    let mut i = start;
+    while i < s.len() {
+        let c = s[i];
+        let r = if c == b'.' && !element_start {
+            elements += 1;
+            element_start = true;
+            i += 1;
+            continue;
+        } else if element_start && !digit_first {
+            if hyphen { is_az_hyphen(c) } else { is_az_(c) }
+        } else if hyphen { is_az09_hyphen(c) } else { is_az09_(c) };
+        if r.is_err() { return Err(()) }
+        element_start = false;
+        i += 1;
     }
+    if element_start || elements < 2 { Err(()) } else { Ok(()) }
 }
 
-fn is_valid_unique_conn_name(mut x: std::slice::Iter<u8>) -> Result<(), ()> {
-    let mut elements = 1;
-    'outer: loop {
-        let c = *x.next().ok_or(())?;
-        is_az09_hyphen(c)?;
-        while let Some(&c) = x.next() {
-            if c == b'.' {
-                elements += 1;
-                continue 'outer;
+pub const fn is_valid_interface_name(s: &[u8]) -> Result<(), ()> {
+    if s.len() > 255 { return Err(()) }
+    is_valid_dotted_name(s, 0, false, false)
+}
+
+pub const fn is_valid_bus_name(s: &[u8]) -> Result<(), ()> {
+    if s.len() > 255 { return Err(()) }
+    if !s.is_empty() && s[0] == b':' { is_valid_dotted_name(s, 1, true, true) }
+    else { is_valid_dotted_name(s, 0, true, false) }
+}
+
+pub const fn is_valid_object_path(s: &[u8]) -> Result<(), ()> {
+    if s.is_empty() || s[0] != b'/' { return Err(()) };
+    if s.len() == 1 { return Ok(()) };
+
+    let mut i = 1;
+    let mut element_start = true;
+    while i < s.len() {
+        if s[i] == b'/' {
+            if element_start { return Err(()) }
+            element_start = true;
+        } else if is_az09_(s[i]).is_ok() {
+            element_start = false;
+        } else { return Err(()) }
+        i += 1;
+    }
+    if element_start { Err(()) } else { Ok(()) }
+}
+
+const BASIC_TYPES: &[u8] = b"ybnqiuxtdhsog";
+
+const fn is_basic_type(c: u8) -> bool {
+    let mut i = 0;
+    while i < BASIC_TYPES.len() {
+        if BASIC_TYPES[i] == c { return true; }
+        i += 1;
+    }
+    false
+}
+
+// The signature functions below are const fns, so they can't use iterators or the ? operator.
+// Errors are the byte offset of the problem and a static string describing it, to be used in
+// compile time error messages.
+
+// Returns the position of the first unmatched ')', or the end of the string.
+const fn sig_multi(s: &[u8], mut pos: usize, arrs: u8, structs: u8) -> Result<usize, (usize, &'static str)> {
+    while pos < s.len() {
+        if s[pos] == b')' { return Ok(pos) }
+        pos = match sig_single(s, pos, arrs, structs) {
+            Ok(p) => p,
+            Err(e) => return Err(e),
+        };
+    }
+    Ok(pos)
+}
+
+// Returns the position after the single complete type starting at pos.
+const fn sig_single(s: &[u8], pos: usize, arrs: u8, structs: u8) -> Result<usize, (usize, &'static str)> {
+    if pos >= s.len() { return Err((pos, "incomplete type")) }
+    let c = s[pos];
+    if is_basic_type(c) || c == b'v' { return Ok(pos + 1); }
+    match c {
+        b'a' => { // Array
+            if arrs >= 32 { return Err((pos, "arrays nested more than 32 levels deep")) }
+            if pos + 1 < s.len() && s[pos + 1] == b'{' { // Dict
+                if pos + 2 >= s.len() { return Err((pos + 2, "incomplete type")) }
+                if !is_basic_type(s[pos + 2]) { return Err((pos + 2, "dict key must be a basic type")) }
+                if pos + 3 < s.len() && s[pos + 3] == b'}' { return Err((pos + 3, "dict entry must have a value")) }
+                match sig_single(s, pos + 3, arrs + 1, structs) {
+                    Ok(p) if p < s.len() && s[p] == b'}' => Ok(p + 1),
+                    Ok(p) if p < s.len() => Err((p, "dict entry must have exactly one key and one value")),
+                    Ok(_) => Err((pos + 1, "missing '}'")),
+                    Err(e) => Err(e),
+                }
+            } else {
+                sig_single(s, pos + 1, arrs + 1, structs)
+            }
+        },
+        b'(' => {
+            if structs >= 32 { return Err((pos, "structs nested more than 32 levels deep")) }
+            match sig_multi(s, pos + 1, arrs, structs + 1) {
+                Ok(p) if p == pos + 1 && p < s.len() => Err((pos, "empty struct")),
+                Ok(p) if p < s.len() => Ok(p + 1),
+                Ok(_) => Err((pos, "missing ')'")),
+                Err(e) => Err(e),
             }
-            is_az09_hyphen(c)?;
-        }
-        return if elements > 1 { Ok(()) } else { Err(()) }
+        },
+        b'{' => Err((pos, "dict entry outside of an array")),
+        b')' => Err((pos, "unmatched ')'")),
+        b'}' => Err((pos, "unmatched '}'")),
+        _ => Err((pos, "unknown type code")),
     }
 }
 
-pub fn is_valid_bus_name(s: &[u8]) -> Result<(), ()> {
-    if s.len() > 255 { return Err(()); }
-    let mut x = s.into_iter();
-    let mut c_first = *x.next().ok_or(())?;
-    if c_first == b':' { return is_valid_unique_conn_name(x); }
-    let mut elements = 1;
-    'outer: loop {
-        is_az_hyphen(c_first)?;
-        while let Some(&c) = x.next() {
-            if c == b'.' {
-                elements += 1;
-                c_first = *x.next().ok_or(())?;
-                continue 'outer;
+// Appends a description of the single complete type starting at pos, and returns the position
+// after it. The signature must already have been validated.
+const fn explain_single(s: &[u8], pos: usize, e: &mut crate::SignatureExplanation) -> usize {
+    let name = match s[pos] {
+        b'y' => "byte",
+        b'b' => "boolean",
+        b'n' => "int16",
+        b'q' => "uint16",
+        b'i' => "int32",
+        b'u' => "uint32",
+        b'x' => "int64",
+        b't' => "uint64",
+        b'd' => "double",
+        b'h' => "unix fd",
+        b's' => "string",
+        b'o' => "object path",
+        b'g' => "signature",
+        b'v' => "variant",
+        b'a' if s[pos + 1] == b'{' => {
+            e.push("array of dict (");
+            let p = explain_single(s, pos + 2, e);
+            e.push(" \u{2192} ");
+            let p = explain_single(s, p, e);
+            e.push(")");
+            return p + 1;
+        },
+        b'a' => {
+            e.push("array of ");
+            return explain_single(s, pos + 1, e);
+        },
+        b'(' => {
+            e.push("struct (");
+            let mut p = pos + 1;
+            while s[p] != b')' {
+                if p > pos + 1 { e.push(", "); }
+                p = explain_single(s, p, e);
             }
-            is_az09_hyphen(c)?;
-        }
-        return if elements > 1 { Ok(()) } else { Err(()) }
+            e.push(")");
+            return p + 1;
+        },
+        _ => panic!("invalid signature"),
+    };
+    e.push(name);
+    pos + 1
+}
+
+// Describes zero or more complete types, separated by commas.
+pub const fn explain_multi(s: &[u8]) -> crate::SignatureExplanation {
+    let mut e = crate::SignatureExplanation::new();
+    let mut p = 0;
+    while p < s.len() {
+        if p > 0 { e.push(", "); }
+        p = explain_single(s, p, &mut e);
     }
+    e
 }
 
-pub fn is_valid_object_path(s: &[u8]) -> Result<(), ()> {
-    let mut x = s.into_iter();
-    let c = x.next();
-    if c != Some(&b'/') { Err(())? };
-    if s.len() == 1 { return Ok(()) };
+// Returns the position of the first nul byte, if any.
+const fn find_nul(s: &[u8]) -> Option<usize> {
+    let mut i = 0;
+    while i < s.len() {
+        if s[i] == 0 { return Some(i); }
+        i += 1;
+    }
+    None
+}
 
-    'outer: loop {
-        let c = *x.next().ok_or(())?;
-        is_az09_(c)?;
-        while let Some(&c) = x.next() {
-            if c == b'/' { continue 'outer; }
-            is_az09_(c)?;
-        }
-        return Ok(());
+// Returns the length of the first single complete type, if any.
+pub const fn single_len(s: &[u8]) -> Option<usize> {
+    match sig_single(s, 0, 0, 0) {
+        Ok(p) => Some(p),
+        Err(_) => None,
     }
 }
 
-const BASIC_TYPES: &[u8] = b"ybnqiuxtdhsog";
+// Like is_valid_signature_single, but with the position and a description of the error.
+pub const fn signature_single_error(s: &[u8]) -> Result<(), (usize, &'static str)> {
+    if s.len() > 255 { return Err((255, "signature longer than 255 bytes")) }
+    if let Some(p) = find_nul(s) { return Err((p, "interior nul byte")) }
+    match sig_single(s, 0, 0, 0) {
+        Ok(pos) if pos == s.len() => Ok(()),
+        Ok(pos) => Err((pos, "more than one complete type")),
+        Err(e) => Err(e),
+    }
+}
 
-fn sig_multi(s: &[u8], arrs: u8, structs: u8) -> Option<usize> {
-    let mut pos = 0;
-    while pos < s.len() {
-        if s.get(pos) == Some(&b')') { return Some(pos) }
-        pos += sig_single(&s[pos..], arrs, structs)?;
-    }
-    Some(pos)
-}
-
-pub (crate) fn sig_single(s: &[u8], arrs: u8, structs: u8) -> Option<usize> {
-    s.first().and_then(|c| {
-        if BASIC_TYPES.into_iter().any(|x| x == c) { Some(1) }
-        else {
-            Some(1 + match c {
-                b'v' => 0, // Variant
-                b'a' => { // Array
-                    if arrs >= 32 { None? };
-                    if s.get(1) == Some(&b'{') { // Dict
-                        let c = s.get(2)?;
-                        if !BASIC_TYPES.into_iter().any(|x| x == c) { None? };
-                        let pos = 3 + sig_single(&s[3..], arrs+1, structs)?;
-                        if s.get(pos)? != &b'}' { None? }
-                        pos
-                    } else {
-                        sig_single(&s[1..], arrs+1, structs)?
-                    }
-                },
-                b'(' => {
-                    if structs >= 32 { None? };
-                    let pos = 1 + sig_multi(&s[1..], arrs, structs+1)?;
-                    if pos == 1 || s.get(pos)? != &b')' { None? }
-                    pos
-                },
-                _ => None?,
-            })
-        }
-    })
-}
-
-pub fn is_valid_signature_single(s: &[u8]) -> Result<(), ()> {
-    if s.len() > 255 { Err(())? }
-    let pos = sig_single(s, 0, 0).ok_or(())?;
-    return if pos == s.len() { Ok(()) } else { Err(()) }
-}
-
-pub fn is_valid_signature_multi(s: &[u8]) -> Result<(), ()> {
-    if s.len() > 255 { Err(())? }
-    let pos = sig_multi(s, 0, 0).ok_or(())?;
-    return if pos == s.len() { Ok(()) } else { Err(()) }
+// Like is_valid_signature_multi, but with the position and a description of the error.
+pub const fn signature_multi_error(s: &[u8]) -> Result<(), (usize, &'static str)> {
+    if s.len() > 255 { return Err((255, "signature longer than 255 bytes")) }
+    if let Some(p) = find_nul(s) { return Err((p, "interior nul byte")) }
+    match sig_multi(s, 0, 0, 0) {
+        Ok(pos) if pos == s.len() => Ok(()),
+        Ok(pos) => Err((pos, "unmatched ')'")),
+        Err(e) => Err(e),
+    }
+}
+
+pub const fn is_valid_signature_single(s: &[u8]) -> Result<(), ()> {
+    match signature_single_error(s) {
+        Ok(()) => Ok(()),
+        Err(_) => Err(()),
+    }
+}
+
+pub const fn is_valid_signature_multi(s: &[u8]) -> Result<(), ()> {
+    match signature_multi_error(s) {
+        Ok(()) => Ok(()),
+        Err(_) => Err(()),
+    }
 }
 
 #[test]
@@ -196,6 +303,9 @@ fn interface() {
     assert!(is_valid_interface_name(b"ZZZ.1Hello").is_err());
     assert!(is_valid_interface_name(b"Hello.W0rld").is_ok());
     assert!(is_valid_interface_name(b":1.54").is_err());
+    assert!(is_valid_interface_name(b"org..freedesktop.Foo").is_err());
+    assert!(is_valid_interface_name(b".org.Foo").is_err());
+    assert!(is_valid_interface_name(b"org.Foo-Bar").is_err());
 }
 
 #[test]
@@ -208,6 +318,10 @@ fn bus() {
     assert!(is_valid_bus_name(b"Hello.W0rld").is_ok());
     assert!(is_valid_bus_name(b":1.54").is_ok());
     assert!(is_valid_bus_name(b"1.54").is_err());
+    assert!(is_valid_bus_name(b"org.example-1.Foo").is_ok());
+    assert!(is_valid_bus_name(b"org..example").is_err());
+    assert!(is_valid_bus_name(b":1..54").is_err());
+    assert!(is_valid_bus_name(b":").is_err());
 }
 
 #[test]
@@ -246,4 +360,20 @@ fn signature() {
     assert!(is_valid_signature_multi(b"").is_ok());
     assert!(is_valid_signature_multi(b"dbus)").is_err());
 
+    assert_eq!(signature_single_error(b"()"), Err((0, "empty struct")));
+    assert_eq!(signature_single_error(b"{ss}"), Err((0, "dict entry outside of an array")));
+    assert_eq!(signature_single_error(b"a{vs}"), Err((2, "dict key must be a basic type")));
+    assert_eq!(signature_single_error(b"a{s}"), Err((3, "dict entry must have a value")));
+    assert_eq!(signature_single_error(b"a{sss}"), Err((4, "dict entry must have exactly one key and one value")));
+    assert_eq!(signature_single_error(b"(ii"), Err((0, "missing ')'")));
+    assert_eq!(signature_single_error(b"ii"), Err((1, "more than one complete type")));
+    assert_eq!(signature_single_error(b"a"), Err((1, "incomplete type")));
+    assert_eq!(signature_single_error(b"z"), Err((0, "unknown type code")));
+    assert_eq!(signature_single_error(b"(i\0i)"), Err((2, "interior nul byte")));
+    assert_eq!(signature_single_error(b"i\0"), Err((1, "interior nul byte")));
+    assert_eq!(signature_multi_error(b"ii)"), Err((2, "unmatched ')'")));
+    assert_eq!(signature_multi_error(b"a{sv}x("), Err((6, "missing ')'")));
+    assert_eq!(signature_single_error(b"a{sa{sv}"), Err((1, "missing '}'")));
+    assert_eq!(signature_single_error(b"(a{sv}(z))"), Err((7, "unknown type code")));
+
 }
@@ -36,7 +36,7 @@ fn array_append<T: Arg, F: FnMut(&T, &mut IterAppend)>(z: &[T], i: &mut IterAppe
 
     // Can we do append_fixed_array?
     let a = (T::ARG_TYPE, mem::size_of::<T>());
-    let can_fixed_array = (zlen > 1) && (z.len() == zlen as usize) && FIXED_ARRAY_ALIGNMENTS.iter().any(|&v| v == a);
+    let can_fixed_array = T::FIXED_ARRAY && (zlen > 1) && (z.len() == zlen as usize) && FIXED_ARRAY_ALIGNMENTS.iter().any(|&v| v == a);
 
     i.append_container(ArgType::Array, Some(T::signature().as_cstr()), |s|
         if can_fixed_array { unsafe { check("dbus_message_iter_append_fixed_array",
@@ -47,9 +47,9 @@ fn array_append<T: Arg, F: FnMut(&T, &mut IterAppend)>(z: &[T], i: &mut IterAppe
 
 /// Appends a D-Bus array. Note: In case you have a large array of a type that implements FixedArray,
 /// using this method will be more efficient than using an Array.
-impl<'a, T: Arg + Append + Clone> Append for &'a [T] {
+impl<'a, T: Arg + Append> Append for &'a [T] {
     fn append_by_ref(&self, i: &mut IterAppend) {
-        array_append(self, i, |arg, s| arg.clone().append(s));
+        array_append(self, i, |arg, s| arg.append_by_ref(s));
     }
 }
 
@@ -63,6 +63,9 @@ impl<'a, T: Arg + RefArg> RefArg for &'a [T] {
     fn as_any(&self) -> &dyn any::Any where Self: 'static { self }
     #[inline]
     fn as_any_mut(&mut self) -> &mut dyn any::Any where Self: 'static { self }
+    fn as_iter<'b>(&'b self) -> Option<Box<dyn Iterator<Item=&'b dyn RefArg> + 'b>> {
+        Some(Box::new(self.iter().map(|b| b as &dyn RefArg)))
+    }
     #[inline]
     fn as_static_inner(&self, index: usize) -> Option<&(dyn RefArg + 'static)> where Self: 'static {
         self.get(index).map(|x| x as &dyn RefArg)
@@ -278,9 +281,10 @@ impl<T: Arg> Arg for Vec<T> {
     fn signature() -> Signature<'static> { Signature::from(format!("a{}", T::signature())) }
 }
 
+/// Appends a D-Bus array. Arrays of FixedArray types are appended in one go.
 impl<T: Arg + Append> Append for Vec<T> {
     fn append_by_ref(&self, i: &mut IterAppend) {
-        Array::new(self).append_by_ref(i);
+        array_append(self, i, |arg, s| arg.append_by_ref(s));
     }
 }
 
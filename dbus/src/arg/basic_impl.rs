@@ -49,6 +49,7 @@ macro_rules! integer_impl {
 
 impl Arg for $t {
     const ARG_TYPE: ArgType = ArgType::$s;
+    const FIXED_ARRAY: bool = true;
     #[inline]
     fn signature() -> Signature<'static> { unsafe { Signature::from_slice_unchecked($f) } }
 }
@@ -145,6 +146,7 @@ refarg_impl!(bool, _i, Some(if *_i { 1 } else { 0 }), None, Some(if *_i { 1 as u
 
 impl Arg for f64 {
     const ARG_TYPE: ArgType = ArgType::Double;
+    const FIXED_ARRAY: bool = true;
     fn signature() -> Signature<'static> { unsafe { Signature::from_slice_unchecked("d\0") } }
 }
 impl Append for f64 {
@@ -16,6 +16,8 @@ pub mod messageitem;
 
 pub use self::msgarg::{Arg, FixedArray, Get, DictKey, Append, RefArg, AppendAll, ReadAll, ArgAll,
     cast, cast_mut, prop_cast, PropMap};
+#[doc(hidden)]
+pub use self::msgarg::has_duplicate_keys;
 pub use self::array_impl::{Array, Dict};
 pub use self::variantstruct_impl::Variant;
 
@@ -194,6 +196,46 @@ impl<'a> IterAppend<'a> {
 }
 
 
+// Used by the args! macro, so that it can append to both a Message and an IterAppend.
+#[doc(hidden)]
+pub trait AppendTarget {
+    fn append_one<T: Append>(&mut self, a: T);
+
+    // Borrows the target through method call syntax, so that a `&mut IterAppend` binding
+    // does not have to be declared mut.
+    fn append_target(&mut self) -> &mut Self { self }
+}
+
+impl AppendTarget for Message {
+    fn append_one<T: Append>(&mut self, a: T) { IterAppend::new(self).append(a) }
+}
+
+impl<'a> AppendTarget for IterAppend<'a> {
+    fn append_one<T: Append>(&mut self, a: T) { self.append(a) }
+}
+
+/// Appends any number of arguments to a `Message` or an `IterAppend`.
+///
+/// Unlike `append1`, `append2` etc, this works for any number of arguments and does not take
+/// the message by value.
+///
+/// # Example
+///
+/// ```
+/// let mut m = dbus::Message::new_signal("/", "org.example.Foo", "Changed").unwrap();
+/// dbus::args!(m, 1u8, "two", 3.0f64, vec![4u32]);
+/// assert_eq!(m.read2::<u8, &str>().unwrap(), (1, "two"));
+/// ```
+#[macro_export]
+macro_rules! args {
+    ($target: expr $(, $arg: expr)* $(,)?) => {{
+        #[allow(unused_imports)]
+        use $crate::arg::AppendTarget as _;
+        let _target = $target.append_target();
+        $( _target.append_one($arg); )*
+    }};
+}
+
 
 #[derive(Clone, Copy)]
 /// Helper struct for retrieve one or more arguments from a Message.
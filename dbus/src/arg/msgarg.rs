@@ -16,6 +16,11 @@ pub trait Arg {
     const ARG_TYPE: ArgType;
     /// The corresponding D-Bus type signature for this type.
     fn signature() -> Signature<'static>;
+
+    // Set by the FixedArray types in this crate, whose slices can be appended in one go
+    // without calling Append for every element.
+    #[doc(hidden)]
+    const FIXED_ARRAY: bool = false;
 }
 
 /// Helper trait to introspect many arguments.
@@ -152,6 +157,57 @@ pub fn prop_cast<'a, T: 'static>(map: &'a PropMap, key: &str) -> Option<&'a T> {
     map.get(key).and_then(|v| cast(&v.0))
 }
 
+// Used by the propmap! macro to reject duplicate keys at compile time.
+#[doc(hidden)]
+pub const fn has_duplicate_keys(keys: &[&str]) -> bool {
+    const fn str_eq(a: &[u8], b: &[u8]) -> bool {
+        if a.len() != b.len() { return false; }
+        let mut i = 0;
+        while i < a.len() {
+            if a[i] != b[i] { return false; }
+            i += 1;
+        }
+        true
+    }
+    let mut i = 0;
+    while i < keys.len() {
+        let mut j = i + 1;
+        while j < keys.len() {
+            if str_eq(keys[i].as_bytes(), keys[j].as_bytes()) { return true; }
+            j += 1;
+        }
+        i += 1;
+    }
+    false
+}
+
+/// Creates a `PropMap` from string literal keys and values, boxing each value into a variant.
+///
+/// Duplicate keys are rejected at compile time.
+///
+/// # Example
+///
+/// ```
+/// use dbus::arg::prop_cast;
+/// let p = dbus::propmap! { "Name" => String::from("eth0"), "Mtu" => 1500u32 };
+/// assert_eq!(prop_cast::<u32>(&p, "Mtu"), Some(&1500));
+/// assert_eq!(prop_cast::<String>(&p, "Name").map(|s| &**s), Some("eth0"));
+/// ```
+///
+/// ```compile_fail
+/// let p = dbus::propmap! { "Mtu" => 1500u32, "Mtu" => 9000u32 };
+/// ```
+#[macro_export]
+macro_rules! propmap {
+    ($($k: literal => $v: expr),* $(,)?) => {{
+        const _: () = assert!(!$crate::arg::has_duplicate_keys(&[$($k),*]), "Duplicate key in propmap!");
+        #[allow(unused_mut)]
+        let mut m = $crate::arg::PropMap::new();
+        $( m.insert(String::from($k), $crate::arg::Variant(Box::new($v) as Box<dyn $crate::arg::RefArg>)); )*
+        m
+    }};
+}
+
 /// If a type implements this trait, it means the size and alignment is the same
 /// as in D-Bus. This means that you can quickly append and get slices of this type.
 ///
@@ -331,6 +387,102 @@ mod test {
 
     use std::collections::HashMap;
 
+    #[test]
+    fn propmap_macro() {
+        use crate::arg::{prop_cast, PropMap};
+        let p = crate::propmap! {
+            "Active" => true,
+            "Addresses" => vec![String::from("10.0.0.1")],
+        };
+        assert_eq!(p.len(), 2);
+        assert_eq!(prop_cast::<bool>(&p, "Active"), Some(&true));
+        assert_eq!(prop_cast::<Vec<String>>(&p, "Addresses").unwrap()[0], "10.0.0.1");
+        let e: PropMap = crate::propmap! {};
+        assert!(e.is_empty());
+        assert!(crate::arg::has_duplicate_keys(&["a", "b", "a"]));
+        assert!(!crate::arg::has_duplicate_keys(&["a", "b", "ab"]));
+    }
+
+    #[test]
+    fn args_macro() {
+        use crate::arg::IterAppend;
+        let mut m = Message::new_signal("/", "com.example.hello", "Hello").unwrap();
+        crate::args!(m, 1u8, "two");
+        {
+            let mut i = IterAppend::new(&mut m);
+            let i2 = &mut i;
+            crate::args!(i2, 3u32, Path::from("/four"),);
+        }
+        crate::args!(m);
+        let mut it = m.iter_init();
+        assert_eq!(it.read::<u8>().unwrap(), 1);
+        assert_eq!(it.read::<&str>().unwrap(), "two");
+        assert_eq!(it.read::<u32>().unwrap(), 3);
+        assert_eq!(it.read::<Path>().unwrap(), Path::from("/four"));
+        assert!(!it.next());
+    }
+
+    #[test]
+    fn args_macro_target_once() {
+        let mut m = Message::new_signal("/", "com.example.hello", "Hello").unwrap();
+        let mut calls = 0;
+        crate::args!({ calls += 1; &mut m }, 1u8, "two");
+        assert_eq!(calls, 1);
+        assert_eq!(m.read2::<u8, &str>().unwrap(), (1, "two"));
+    }
+
+    #[test]
+    fn vec_append() {
+        let bytes: Vec<u8> = (0..100000).map(|x| x as u8).collect();
+        let ints = vec![-1i32, 2, -3];
+        let doubles = vec![0.5f64];
+        let bools = vec![true, false, true];
+        let strings = vec![String::from("a"), String::from("b")];
+        let m = Message::new_signal("/", "com.example.hello", "Hello").unwrap()
+            .append3(&bytes, &ints, &doubles).append2(&bools, &strings);
+        let mut it = m.iter_init();
+        assert_eq!(it.read::<&[u8]>().unwrap(), &*bytes);
+        assert_eq!(it.read::<Vec<i32>>().unwrap(), ints);
+        assert_eq!(it.read::<Vec<f64>>().unwrap(), doubles);
+        assert_eq!(it.read::<Vec<bool>>().unwrap(), bools);
+        assert_eq!(it.read::<Vec<String>>().unwrap(), strings);
+    }
+
+    #[test]
+    fn vec_append_custom() {
+        // Same size and type code as u32, but with its own Append impl, which must be used.
+        #[repr(u32)]
+        #[derive(Copy, Clone)]
+        enum Level { Low = 0, High = 1 }
+        impl super::Arg for Level {
+            const ARG_TYPE: ArgType = ArgType::UInt32;
+            fn signature() -> Signature<'static> { Signature::make::<u32>() }
+        }
+        impl super::Append for Level {
+            fn append_by_ref(&self, i: &mut super::IterAppend) {
+                i.append(match self { Level::Low => 10u32, Level::High => 20u32 })
+            }
+        }
+
+        let m = Message::new_signal("/", "com.example.hello", "Hello").unwrap()
+            .append1(vec![Level::Low, Level::High]);
+        assert_eq!(m.read1::<Vec<u32>>().unwrap(), vec![10, 20]);
+
+        let m = Message::new_signal("/", "com.example.hello", "Hello").unwrap()
+            .append1(&[Level::High, Level::Low][..]);
+        assert_eq!(m.read1::<Vec<u32>>().unwrap(), vec![20, 10]);
+    }
+
+    #[test]
+    fn slice_as_iter() {
+        let v = vec![String::from("a"), String::from("b")];
+        let s: &[String] = &v;
+        let items: Vec<_> = RefArg::as_iter(&s).unwrap().map(|x| x.as_str().unwrap().to_string()).collect();
+        assert_eq!(items, v);
+        let items: Vec<_> = v.as_iter().unwrap().map(|x| x.as_str().unwrap()).collect();
+        assert_eq!(items, ["a", "b"]);
+    }
+
     #[test]
     fn refarg() {
         let c = Channel::get_private(BusType::Session).unwrap();
@@ -454,6 +454,19 @@ impl<'a, T: BlockingSender, C: std::ops::Deref<Target=T>> Proxy<'a, C> {
     }
 }
 
+impl<'a, T: channel::Sender, C: std::ops::Deref<Target=T>> Proxy<'a, C> {
+    /// Make a method call without waiting for a reply.
+    ///
+    /// The message has the no-reply flag set, so the remote side will not send a reply either.
+    /// Useful for notification style calls where the result does not matter.
+    pub fn method_call_noreply<'i, 'm, A: AppendAll, I: Into<Interface<'i>>, M: Into<Member<'m>>>(&self, i: I, m: M, args: A) -> Result<(), Error> {
+        let mut msg = Message::method_call(&self.destination, &self.path, &i.into(), &m.into());
+        args.append(&mut IterAppend::new(&mut msg));
+        msg.set_no_reply(true);
+        self.connection.send(msg).map(|_| ()).map_err(|_| Error::new_failed("Failed to send message"))
+    }
+}
+
 /// Internal helper trait
 pub trait MakeSignal<G, S, T> {
     /// Internal helper trait
@@ -468,6 +481,18 @@ fn test_add_match() {
     c.remove_match(x).unwrap();
 }
 
+#[test]
+fn test_method_call_noreply() {
+    let c = RefCell::new(vec!());
+    let proxy = Proxy::new("com.example.dbusrs.noreply", "/", Duration::from_secs(1), &c);
+    proxy.method_call_noreply("com.example.dbusrs.noreply", "Notify", ("hello", 5u32)).unwrap();
+    let msgs = c.into_inner();
+    assert_eq!(msgs.len(), 1);
+    assert!(msgs[0].get_no_reply());
+    assert_eq!(&*msgs[0].member().unwrap(), "Notify");
+    assert_eq!(msgs[0].read2::<&str, u32>().unwrap(), ("hello", 5));
+}
+
 #[test]
 fn test_conn_send_sync() {
     fn is_send<T: Send>(_: &T) {}
@@ -196,6 +196,23 @@ impl Message {
         unsafe { ffi::dbus_message_set_auto_start(self.msg, if v { 1 } else { 0 }) }
     }
 
+    /// Returns true if the caller is prepared to wait for interactive authorization.
+    ///
+    /// Note: This requires libdbus 1.10 or later, older versions fail to link.
+    pub fn get_allow_interactive_authorization(&self) -> bool {
+        unsafe { ffi::dbus_message_get_allow_interactive_authorization(self.msg) != 0 }
+    }
+
+    /// Sets whether the receiver may ask the user for authorization (e g through polkit)
+    /// before replying to this method call.
+    ///
+    /// Defaults to false.
+    ///
+    /// Note: This requires libdbus 1.10 or later, older versions fail to link.
+    pub fn set_allow_interactive_authorization(&mut self, v: bool) {
+        unsafe { ffi::dbus_message_set_allow_interactive_authorization(self.msg, if v { 1 } else { 0 }) }
+    }
+
     /// Add one or more MessageItems to this Message.
     ///
     /// Note: using `append1`, `append2` or `append3` might be faster, especially for large arrays.
@@ -532,6 +549,46 @@ impl fmt::Debug for Message {
     }
 }
 
+/// Creates a method call or signal `Message`, with all names checked at compile time.
+///
+/// The arguments, if any, are given as a tuple and appended through `AppendAll`.
+///
+/// # Example
+///
+/// ```
+/// let m = dbus::message!(method_call, destination: "org.freedesktop.DBus", path: "/org/freedesktop/DBus",
+///     interface: "org.freedesktop.DBus", member: "NameHasOwner", args: ("org.example.Foo",));
+/// assert_eq!(m.read1::<&str>().unwrap(), "org.example.Foo");
+///
+/// let s = dbus::message!(signal, path: "/org/example", interface: "org.example.Foo", member: "Changed",
+///     args: (5u32, "five"));
+/// assert_eq!(s.read2::<u32, &str>().unwrap(), (5, "five"));
+/// ```
+///
+/// An invalid name fails to compile:
+///
+/// ```compile_fail
+/// let m = dbus::message!(signal, path: "/org/example", interface: "Foo", member: "Changed");
+/// ```
+#[macro_export]
+macro_rules! message {
+    (method_call, destination: $d: literal, path: $p: literal, interface: $i: literal, member: $m: literal
+        $(, args: $args: expr)? $(,)?) => {{
+        #[allow(unused_mut)]
+        let mut msg = $crate::Message::method_call(&$crate::bus_name!($d), &$crate::object_path!($p),
+            &$crate::interface_name!($i), &$crate::member_name!($m));
+        $( msg.append_all($args); )?
+        msg
+    }};
+    (signal, path: $p: literal, interface: $i: literal, member: $m: literal $(, args: $args: expr)? $(,)?) => {{
+        #[allow(unused_mut)]
+        let mut msg = $crate::Message::signal(&$crate::object_path!($p), &$crate::interface_name!($i),
+            &$crate::member_name!($m));
+        $( msg.append_all($args); )?
+        msg
+    }};
+}
+
 #[cfg(test)]
 mod test {
     use crate::{Message};
@@ -548,6 +605,34 @@ mod test {
         assert!(m.get_no_reply());
     }
 
+    #[test]
+    fn message_macro() {
+        let m = crate::message!(method_call, destination: ":1.14", path: "/", interface: "org.test.rust",
+            member: "Test");
+        assert_eq!(m.msg_type(), crate::MessageType::MethodCall);
+        assert_eq!(&*m.destination().unwrap(), ":1.14");
+        assert_eq!(&*m.member().unwrap(), "Test");
+        assert!(m.get1::<u8>().is_none());
+
+        let s = crate::message!(signal, path: "/test", interface: "org.test.rust", member: "Changed",
+            args: (1u8, vec!["a", "b"]),);
+        assert_eq!(s.msg_type(), crate::MessageType::Signal);
+        assert_eq!(&*s.path().unwrap(), "/test");
+        assert_eq!(s.read2::<u8, Vec<&str>>().unwrap(), (1, vec!["a", "b"]));
+    }
+
+    #[test]
+    fn set_flags() {
+        let mut m = Message::new_method_call("org.test.rust", "/", "org.test.rust", "Test").unwrap();
+        assert!(m.get_auto_start());
+        m.set_auto_start(false);
+        assert!(!m.get_auto_start());
+
+        assert!(!m.get_allow_interactive_authorization());
+        m.set_allow_interactive_authorization(true);
+        assert!(m.get_allow_interactive_authorization());
+    }
+
     #[test]
     fn set_valid_sender() {
         let mut m = Message::new_method_call("org.test.rust", "/", "org.test.rust", "Test").unwrap();
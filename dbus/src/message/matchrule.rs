@@ -1,6 +1,7 @@
 use crate::{Message, MessageType};
 use crate::strings::{BusName, Path, Interface, Member};
 use crate::message::parser;
+use std::borrow::Cow;
 
 #[derive(Clone, Debug, Default)]
 /// A "match rule", that can match Messages on its headers.
@@ -24,6 +25,11 @@ pub struct MatchRule<'a> {
     pub member: Option<Member<'a>>,
     /// If true, also receive messages not intended for us. Defaults to false.
     pub eavesdrop: bool,
+    /// Match on the first argument of the message, which must be a string
+    pub arg0: Option<Cow<'a, str>>,
+    /// If true, arg0 is treated as a bus name or interface namespace, i e "org.example" will match
+    /// "org.example" and "org.example.Foo" but not "org.examples". Defaults to false.
+    pub arg0_is_namespace: bool,
     _more_fields_may_come: (),
 }
 
@@ -49,13 +55,25 @@ impl<'a> MatchRule<'a> {
         if let Some(ref x) = self.interface { v.push(("interface", &x)) };
         if let Some(ref x) = self.member { v.push(("member", &x)) };
         if self.eavesdrop { v.push(("eavesdrop", "true")) };
+        let an = if self.arg0_is_namespace { "arg0namespace" } else { "arg0" };
+        if let Some(ref x) = self.arg0 { v.push((an, x)) };
 
-        // For now we don't need to worry about internal quotes in strings as those are not valid names.
-        // If we start matching against arguments, we need to worry.
-        let v: Vec<_> = v.into_iter().map(|(k, v)| format!("{}='{}'", k, v)).collect();
+        // Names can't contain quotes, but arg0 can. A quote is written by ending the quoted
+        // string, adding an escaped quote, and starting a new quoted string.
+        let v: Vec<_> = v.into_iter().map(|(k, v)| format!("{}='{}'", k, v.replace('\'', "'\\''"))).collect();
         v.join(",")
     }
 
+    fn arg0_match(&self, msg: &Message) -> bool {
+        if let Some(ref x) = self.arg0 {
+            if let Some(a) = msg.get1::<&str>() {
+                if a != x {
+                    self.arg0_is_namespace && a.starts_with(&**x) && a[x.len()..].starts_with('.')
+                } else { true }
+            } else { false }
+        } else { true }
+    }
+
     fn path_match(&self, msg: &Message) -> bool {
         if let Some(ref x) = self.path {
             if let Some(ref p) = msg.path() {
@@ -81,6 +99,7 @@ impl<'a> MatchRule<'a> {
         if !self.path_match(msg) { return false; }
         if self.interface.is_some() && msg.interface() != self.interface { return false; };
         if self.member.is_some() && msg.member() != self.member { return false; };
+        if !self.arg0_match(msg) { return false; }
         true
     }
 
@@ -114,6 +133,8 @@ impl<'a> MatchRule<'a> {
             member: self.member.as_ref().map(|x| x.clone().into_static()),
             path_is_namespace: self.path_is_namespace,
             eavesdrop: self.eavesdrop,
+            arg0: self.arg0.as_ref().map(|x| Cow::Owned(x.clone().into_owned())),
+            arg0_is_namespace: self.arg0_is_namespace,
             _more_fields_may_come: (),
         }
     }
@@ -163,6 +184,24 @@ impl<'a> MatchRule<'a> {
         self
     }
 
+    /// Sets the MatchRule to match on the first argument of the message, which must be a string
+    ///
+    /// E g, to follow a single name on NameOwnerChanged.
+    pub fn with_arg0(mut self, arg0: impl Into<Cow<'a, str>>) -> Self {
+        self.arg0 = Some(arg0.into());
+        self.arg0_is_namespace = false;
+        self
+    }
+
+    /// Sets the MatchRule to match on the first argument of the message and treat it as a namespace
+    ///
+    /// E g, to follow all names starting with "org.example." on NameOwnerChanged.
+    pub fn with_arg0_namespace(mut self, arg0: impl Into<Cow<'a, str>>) -> Self {
+        self.arg0 = Some(arg0.into());
+        self.arg0_is_namespace = true;
+        self
+    }
+
     /// Sets the MatchRule to match on the message type. This will usually be `"signal"`
     pub fn with_type(mut self, ty: MessageType) -> Self {
         self.msg_type = Some(ty);
@@ -170,8 +209,56 @@ impl<'a> MatchRule<'a> {
     }
 
     /// Tries parsing a MatchRule from a String. Please note however that not all features supported
-    /// by DBus are supported by dbus-rs (yet). args and destinations are not supported yet.
+    /// by DBus are supported by dbus-rs (yet). Only arg0 and arg0namespace are supported of the
+    /// argument matches, and destinations are not supported yet.
     pub fn parse(text: &'a str) -> Result<Self, parser::Error> {
         parser::Parser::new(text)?.parse()
     }
-}
\ No newline at end of file
+}
+
+/// Creates a `MatchRule<'static>` from named fields, with all names checked at compile time.
+///
+/// Supported fields are `type` (one of `signal`, `method_call`, `method_return` and `error`),
+/// `sender`, `strict_sender`, `path`, `path_namespace`, `interface`, `member`, `arg0`,
+/// `arg0namespace` and `eavesdrop` (which takes no value).
+///
+/// # Example
+///
+/// ```
+/// let mr = dbus::match_rule!(type: signal, interface: "org.freedesktop.DBus", member: "NameOwnerChanged",
+///     arg0: "org.example.Foo");
+/// assert_eq!(mr.match_str(),
+///     "type='signal',interface='org.freedesktop.DBus',member='NameOwnerChanged',arg0='org.example.Foo'");
+/// ```
+///
+/// An invalid name fails to compile:
+///
+/// ```compile_fail
+/// let mr = dbus::match_rule!(type: signal, path: "/org/example/");
+/// ```
+#[macro_export]
+macro_rules! match_rule {
+    ($($key: ident $(: $value: tt)?),* $(,)?) => {{
+        let mr = $crate::message::MatchRule::new();
+        $( let mr = $crate::__match_rule_field!(mr, $key $(: $value)?); )*
+        mr
+    }};
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __match_rule_field {
+    ($mr: ident, type: signal) => { $mr.with_type($crate::MessageType::Signal) };
+    ($mr: ident, type: method_call) => { $mr.with_type($crate::MessageType::MethodCall) };
+    ($mr: ident, type: method_return) => { $mr.with_type($crate::MessageType::MethodReturn) };
+    ($mr: ident, type: error) => { $mr.with_type($crate::MessageType::Error) };
+    ($mr: ident, sender: $s: literal) => { $mr.with_sender($crate::bus_name!($s)) };
+    ($mr: ident, strict_sender: $s: literal) => { $mr.with_strict_sender($crate::bus_name!($s)) };
+    ($mr: ident, path: $s: literal) => { $mr.with_path($crate::object_path!($s)) };
+    ($mr: ident, path_namespace: $s: literal) => { $mr.with_namespaced_path($crate::object_path!($s)) };
+    ($mr: ident, interface: $s: literal) => { $mr.with_interface($crate::interface_name!($s)) };
+    ($mr: ident, member: $s: literal) => { $mr.with_member($crate::member_name!($s)) };
+    ($mr: ident, arg0: $s: literal) => { $mr.with_arg0($s) };
+    ($mr: ident, arg0namespace: $s: literal) => { $mr.with_arg0_namespace($s) };
+    ($mr: ident, eavesdrop) => { $mr.with_eavesdrop() };
+}
@@ -19,6 +19,7 @@ use crate::strings::{Interface, BusName, Member};
 // destination: "destination" "=" string
 // arg: "arg" 0-63 "=" string
 // arg_path: "arg" 0-63 "path" "=" string
+// arg0namespace: "arg0namespace" "=" string
 // eavesdrop: "eavesdrop" "=" bool
 
 
@@ -158,8 +159,10 @@ impl<'a> Parser<'a> {
         let mut outbuf = String::with_capacity(buf.len());
 
         for c in buf.chars() {
+            let escaped = escape;
+            escape = false;
             match c {
-                '\'' if !escape => {
+                '\'' if !escaped => {
                     quoted = !quoted;
                 }
                 '\\' if !quoted => {
@@ -210,6 +213,14 @@ impl<'a> Parser<'a> {
                     match_rule.path_is_namespace = true;
                     Ok(())
                 }
+                "arg0" => {
+                    match_rule = match_rule.with_arg0(value);
+                    Ok(())
+                }
+                "arg0namespace" => {
+                    match_rule = match_rule.with_arg0_namespace(value);
+                    Ok(())
+                }
                 "eavesdrop" => {
                     match raw_value {
                         "'true'" | "true" => {
@@ -225,7 +236,7 @@ impl<'a> Parser<'a> {
                     }
                 }
                 _ => {
-                    // Args and Destination are not supported yet.
+                    // Other args and Destination are not supported yet.
                     Err(Error::UnknownKey)
                 }
             }?;
@@ -249,6 +260,56 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_arg0() -> Result<(), Error> {
+        let mr = MatchRule::parse(r"type='signal',member='NameOwnerChanged',arg0='org.example.Foo'")?;
+        assert_eq!(mr.arg0.as_deref(), Some("org.example.Foo"));
+        assert!(!mr.arg0_is_namespace);
+        assert_eq!(mr.match_str(), "type='signal',member='NameOwnerChanged',arg0='org.example.Foo'");
+        let mr = MatchRule::parse(r"arg0namespace='org.example'")?;
+        assert_eq!(mr.arg0.as_deref(), Some("org.example"));
+        assert!(mr.arg0_is_namespace);
+        assert_eq!(mr.match_str(), "arg0namespace='org.example'");
+
+        let mr = MatchRule::new().with_arg0("it's");
+        assert_eq!(mr.match_str(), r"arg0='it'\''s'");
+
+        for a in &["it's", "a'b'c", "'", "a,b", "x=','"] {
+            let mr = MatchRule::new().with_arg0(*a);
+            assert_eq!(MatchRule::parse(&mr.match_str())?.arg0.as_deref(), Some(*a), "{}", mr.match_str());
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_arg0_matches() {
+        use crate::Message;
+        let msg = |a: &str| Message::new_signal("/", "org.freedesktop.DBus", "NameOwnerChanged").unwrap()
+            .append3(a, "", ":1.5");
+        let mr = MatchRule::new().with_arg0("org.example");
+        assert!(mr.matches(&msg("org.example")));
+        assert!(!mr.matches(&msg("org.example.Foo")));
+        let mr = MatchRule::new().with_arg0_namespace("org.example");
+        assert!(mr.matches(&msg("org.example")));
+        assert!(mr.matches(&msg("org.example.Foo")));
+        assert!(!mr.matches(&msg("org.examples")));
+        assert!(!mr.matches(&Message::new_signal("/", "org.example", "Foo").unwrap()));
+    }
+
+    #[test]
+    fn test_match_rule_macro() {
+        let mr = crate::match_rule!(type: method_call, strict_sender: ":1.54", path_namespace: "/org/example",
+            member: "Hello", eavesdrop,);
+        assert_eq!(mr.msg_type, Some(crate::message::MessageType::MethodCall));
+        assert!(mr.strict_sender);
+        assert!(mr.path_is_namespace);
+        assert_eq!(mr.match_str(), "type='method_call',sender=':1.54',path_namespace='/org/example',member='Hello',eavesdrop='true'");
+
+        let mr: MatchRule<'static> = crate::match_rule!(arg0namespace: "org.example");
+        assert_eq!(mr.match_str(), "arg0namespace='org.example'");
+        assert_eq!(crate::match_rule!().match_str(), "");
+    }
+
     #[test]
     fn test_malformed() {
         assert!(MatchRule::parse(r"interface='org.freedesktop.Notifications',member=").is_err());
@@ -55,7 +55,7 @@ impl<'m> $t<'m> {
 
     /// This function creates a new instance of this struct, without checking.
     /// It's up to you to guarantee that s ends with a \0 and is valid.
-    pub unsafe fn from_slice_unchecked(s: &'m str) -> $t<'m> {
+    pub const unsafe fn from_slice_unchecked(s: &'m str) -> $t<'m> {
         let ss = s.as_bytes();
         debug_assert!(ss[ss.len()-1] == 0);
         $t(Cow::Borrowed(s))
@@ -219,6 +219,243 @@ pub struct ErrorName<'a>(Cow<'a, str>);
 
 cstring_wrapper!(ErrorName, dbus_validate_error_name);
 
+// Compile time validation, used by the sig! macro. This cannot call into libdbus,
+// so it uses the plain Rust grammar from dbus-strings instead.
+// Panics with e g "Invalid D-Bus signature a{vs}: dict key must be a basic type at byte 2".
+// A const panic can only print a single &str, so the message is put together by hand.
+const fn signature_error(s: &str, (pos, e): (usize, &'static str)) -> ! {
+    let mut num = [0u8; 20];
+    let mut start = num.len();
+    let mut n = pos;
+    loop {
+        start -= 1;
+        num[start] = b'0' + (n % 10) as u8;
+        n /= 10;
+        if n == 0 { break; }
+    }
+    let num = match std::str::from_utf8(num.split_at(start).1) {
+        Ok(num) => num,
+        Err(_) => "?",
+    };
+
+    let parts = ["Invalid D-Bus signature ", s, ": ", e, " at byte ", num];
+    let mut buf = [0u8; 512];
+    let mut len = 0;
+    let mut i = 0;
+    while i < parts.len() {
+        let p = parts[i].as_bytes();
+        let mut j = 0;
+        while j < p.len() && len < buf.len() {
+            buf[len] = p[j];
+            len += 1;
+            j += 1;
+        }
+        i += 1;
+    }
+    match std::str::from_utf8(buf.split_at(len).0) {
+        Ok(msg) => panic!("{}", msg),
+        Err(_) => panic!("{}", e),
+    }
+}
+
+#[doc(hidden)]
+pub const fn check_signature_single(s: &str) {
+    if let Err(e) = dbus_strings::SignatureSingle::check_const(s) {
+        signature_error(s, e)
+    }
+}
+
+/// Creates a `Signature<'static>` from a string literal, checked at compile time.
+///
+/// The signature must be a single complete type, just like for `Signature::new`.
+///
+/// # Example
+///
+/// ```
+/// let s: dbus::Signature<'static> = dbus::sig!("a{sa{sv}}");
+/// assert_eq!(&*s, "a{sa{sv}}");
+///
+/// // It can also be used in const and static items.
+/// const PROPS: dbus::Signature<'static> = dbus::sig!("a{sv}");
+/// assert_eq!(&*PROPS, "a{sv}");
+/// ```
+///
+/// An invalid signature fails to compile, with an error naming the signature, the problem and
+/// where it is (here: "Invalid D-Bus signature a{vs}: dict key must be a basic type at byte 2"):
+///
+/// ```compile_fail
+/// let s = dbus::sig!("a{vs}");
+/// ```
+///
+/// Raw string literals work too, but interior nul bytes are rejected:
+///
+/// ```compile_fail
+/// let s = dbus::sig!("i\0");
+/// ```
+#[macro_export]
+macro_rules! sig {
+    ($s: literal) => {{
+        const _: () = $crate::strings::check_signature_single($s);
+        unsafe { $crate::Signature::from_slice_unchecked(concat!($s, "\0")) }
+    }};
+}
+
+#[doc(hidden)]
+pub use dbus_strings::SignatureExplanation;
+
+#[doc(hidden)]
+pub const fn explain_signature(s: &str) -> SignatureExplanation {
+    match dbus_strings::SignatureMulti::explain_const(s) {
+        Ok(e) => e,
+        Err(e) => signature_error(s, e),
+    }
+}
+
+/// Describes a signature in human terms, checked at compile time.
+///
+/// The description is a `&'static str`, e g "array of dict (string \u{2192} variant)" for
+/// "a{sv}". This is mostly useful when reading or reviewing complicated signatures.
+/// Several complete types are separated by commas.
+///
+/// # Example
+///
+/// ```
+/// let s: &'static str = dbus::explain_signature!("a{oa{sv}}");
+/// assert_eq!(s, "array of dict (object path \u{2192} array of dict (string \u{2192} variant))");
+/// ```
+///
+/// An invalid signature fails to compile:
+///
+/// ```compile_fail
+/// let s = dbus::explain_signature!("a{vs}");
+/// ```
+#[macro_export]
+macro_rules! explain_signature {
+    ($s: literal) => {{
+        const E: $crate::strings::SignatureExplanation = $crate::strings::explain_signature($s);
+        const S: &str = E.as_str();
+        S
+    }};
+}
+
+#[doc(hidden)]
+pub const fn is_valid_object_path(s: &str) -> bool { dbus_strings::ObjectPath::is_valid_const(s) }
+#[doc(hidden)]
+pub const fn is_valid_interface_name(s: &str) -> bool { dbus_strings::InterfaceName::is_valid_const(s) }
+#[doc(hidden)]
+pub const fn is_valid_bus_name(s: &str) -> bool { dbus_strings::BusName::is_valid_const(s) }
+#[doc(hidden)]
+pub const fn is_valid_member_name(s: &str) -> bool { dbus_strings::MemberName::is_valid_const(s) }
+#[doc(hidden)]
+pub const fn is_valid_error_name(s: &str) -> bool { dbus_strings::ErrorName::is_valid_const(s) }
+
+// Shared by the object_path!, interface_name!, bus_name!, member_name! and error_name! macros.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __checked_string {
+    ($t: ident, $validate: ident, $what: literal, $s: literal) => {{
+        const _: () = assert!($crate::strings::$validate($s), "{}", concat!("Invalid D-Bus ", $what, ": ", $s));
+        unsafe { $crate::strings::$t::from_slice_unchecked(concat!($s, "\0")) }
+    }};
+}
+
+/// Creates a `Path<'static>` from a string literal, checked at compile time.
+///
+/// # Example
+///
+/// ```
+/// let p: dbus::Path<'static> = dbus::object_path!("/org/freedesktop/DBus");
+/// assert_eq!(&*p, "/org/freedesktop/DBus");
+/// ```
+///
+/// An invalid path fails to compile:
+///
+/// ```compile_fail
+/// let p = dbus::object_path!("/foo//bar");
+/// ```
+#[macro_export]
+macro_rules! object_path {
+    ($s: literal) => { $crate::__checked_string!(Path, is_valid_object_path, "object path", $s) };
+}
+
+/// Creates an `Interface<'static>` from a string literal, checked at compile time.
+///
+/// # Example
+///
+/// ```
+/// let i: dbus::strings::Interface<'static> = dbus::interface_name!("org.freedesktop.DBus.Properties");
+/// assert_eq!(&*i, "org.freedesktop.DBus.Properties");
+/// ```
+///
+/// An invalid interface name fails to compile:
+///
+/// ```compile_fail
+/// let i = dbus::interface_name!("org..freedesktop.Foo");
+/// ```
+#[macro_export]
+macro_rules! interface_name {
+    ($s: literal) => { $crate::__checked_string!(Interface, is_valid_interface_name, "interface name", $s) };
+}
+
+/// Creates a `BusName<'static>` from a string literal, checked at compile time.
+///
+/// Both well-known names like "org.freedesktop.DBus" and unique names like ":1.54" are accepted.
+///
+/// # Example
+///
+/// ```
+/// let b: dbus::strings::BusName<'static> = dbus::bus_name!("org.freedesktop.DBus");
+/// assert_eq!(&*b, "org.freedesktop.DBus");
+/// ```
+///
+/// An invalid bus name fails to compile:
+///
+/// ```compile_fail
+/// let b = dbus::bus_name!("org");
+/// ```
+#[macro_export]
+macro_rules! bus_name {
+    ($s: literal) => { $crate::__checked_string!(BusName, is_valid_bus_name, "bus name", $s) };
+}
+
+/// Creates a `Member<'static>` from a string literal, checked at compile time.
+///
+/// # Example
+///
+/// ```
+/// let m: dbus::strings::Member<'static> = dbus::member_name!("GetAll");
+/// assert_eq!(&*m, "GetAll");
+/// ```
+///
+/// An invalid member name fails to compile:
+///
+/// ```compile_fail
+/// let m = dbus::member_name!("Get.All");
+/// ```
+#[macro_export]
+macro_rules! member_name {
+    ($s: literal) => { $crate::__checked_string!(Member, is_valid_member_name, "member name", $s) };
+}
+
+/// Creates an `ErrorName<'static>` from a string literal, checked at compile time.
+///
+/// # Example
+///
+/// ```
+/// let e: dbus::strings::ErrorName<'static> = dbus::error_name!("org.freedesktop.DBus.Error.Failed");
+/// assert_eq!(&*e, "org.freedesktop.DBus.Error.Failed");
+/// ```
+///
+/// An invalid error name fails to compile:
+///
+/// ```compile_fail
+/// let e = dbus::error_name!("Failed");
+/// ```
+#[macro_export]
+macro_rules! error_name {
+    ($s: literal) => { $crate::__checked_string!(ErrorName, is_valid_error_name, "error name", $s) };
+}
+
 #[test]
 fn some_path() {
     let p1: Path = "/valid".into();
@@ -248,3 +485,62 @@ fn reborrow_path() {
 fn make_sig() {
     assert_eq!(&*Signature::make::<(&str, u8)>(), "(sy)");
 }
+
+#[test]
+fn sig_macro() {
+    assert_eq!(crate::sig!("a{sv}"), Signature::new("a{sv}").unwrap());
+    assert_eq!(crate::sig!("(dbus)"), Signature::make::<(f64, bool, u32, String)>());
+    const S: Signature<'static> = crate::sig!("as");
+    assert_eq!(crate::sig!(r"a{sv}"), Signature::new("a{sv}").unwrap());
+    assert_eq!(S, Signature::make::<Vec<String>>());
+
+    let is_valid_signature_single = dbus_strings::SignatureSingle::is_valid_const;
+    #[cfg(not(feature = "no-string-validation"))]
+    for s in &["", "i", "ii", "vi", "{ss}", "a{ss}", "a{vs}", "a{ss}i", "a{oa{sv}}", "()", "(s)",
+        "(sa{sv}(i))", "(sa{sv}(i)", "a", "aaai", "(i))", "a{s}", "a{sii}"] {
+        assert_eq!(is_valid_signature_single(s), Signature::new(*s).is_ok(), "{}", s);
+    }
+    let deep = format!("{}i", "a".repeat(32));
+    assert!(is_valid_signature_single(&deep));
+    assert!(!is_valid_signature_single(&format!("a{}", deep)));
+    let deep = format!("{}i{}", "(".repeat(32), ")".repeat(32));
+    assert!(is_valid_signature_single(&deep));
+    assert!(!is_valid_signature_single(&format!("({})", deep)));
+}
+
+#[test]
+fn object_path_macro() {
+    const ROOT: Path<'static> = crate::object_path!("/");
+    assert_eq!(ROOT, Path::new("/").unwrap());
+    assert_eq!(crate::object_path!("/org/example/_1"), Path::new("/org/example/_1").unwrap());
+
+    #[cfg(not(feature = "no-string-validation"))]
+    for s in &["", "/", "//", "/a/", "/a//b", "a/b", "/a-b", "/a/b_1/C"] {
+        assert_eq!(is_valid_object_path(s), Path::new(*s).is_ok(), "{}", s);
+    }
+}
+
+#[test]
+fn name_macros() {
+    assert_eq!(crate::interface_name!("org.freedesktop.DBus"), Interface::new("org.freedesktop.DBus").unwrap());
+    assert_eq!(crate::bus_name!(":1.54"), BusName::new(":1.54").unwrap());
+    assert_eq!(crate::member_name!("NameOwnerChanged"), Member::new("NameOwnerChanged").unwrap());
+    const E: ErrorName<'static> = crate::error_name!("org.example.Error.Oops");
+    assert_eq!(E, ErrorName::new("org.example.Error.Oops").unwrap());
+
+    #[cfg(not(feature = "no-string-validation"))]
+    for s in &["", "a", "a.b", "a..b", ".a.b", "a.b.", "a.1b", "a-b.c", ":1.54", ":a..b", "_a._b", "a.b!"] {
+        assert_eq!(is_valid_interface_name(s), Interface::new(*s).is_ok(), "interface {}", s);
+        assert_eq!(is_valid_error_name(s), ErrorName::new(*s).is_ok(), "error {}", s);
+        assert_eq!(is_valid_bus_name(s), BusName::new(*s).is_ok(), "bus {}", s);
+        assert_eq!(is_valid_member_name(s), Member::new(*s).is_ok(), "member {}", s);
+    }
+}
+
+#[test]
+fn explain_signature_macro() {
+    assert_eq!(crate::explain_signature!("(dbus)"), "struct (double, boolean, uint32, string)");
+    assert_eq!(crate::explain_signature!("sa{sv}"), "string, array of dict (string \u{2192} variant)");
+    const E: &str = crate::explain_signature!("aay");
+    assert_eq!(E, "array of array of byte");
+}